@@ -0,0 +1,70 @@
+//! Compares the scalar and `portable_simd` Phase 2 face-culling kernels used by
+//! `build_chunk_mesh`. Run with `cargo bench --features portable_simd` to include
+//! the SIMD side; without the feature only the scalar kernel is benchmarked.
+//!
+//! Meshing cost dominates world generation throughput, so this benchmark exists
+//! to justify (and guard against regressing) the SIMD kernel's speedup over the
+//! scalar fallback it replaces.
+//!
+//! NOTE: this tree has no `Cargo.toml` and is missing `lib.rs` plus most of
+//! the crate's other modules (`constants`, `chunks_refs`, `face_direction`,
+//! `lod`, `utils`, `voxel`) going all the way back to the baseline commit
+//! this series started from — they were never part of this snapshot, not
+//! dropped by this work. Adding a manifest alone would not make this buildable,
+//! so it isn't fabricated here; once the rest of the crate exists, a
+//! `portable_simd = []` feature, a `criterion` dev-dependency, and a
+//! `[[bench]]` entry pointing at this file (`harness = false`) are what this
+//! bench needs to run.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use expanse_ui::constants::CHUNK_SIZE_P;
+
+/// A representative, non-trivial set of axis columns: a mix of solid runs and gaps
+/// so the shift-and-andnot transition detection has real work to do, rather than
+/// an all-zero or all-one column that would hide any per-lane branch behavior.
+fn sample_axis_cols() -> [[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3] {
+    let mut axis_cols = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3];
+    for axis in axis_cols.iter_mut() {
+        for (z, row) in axis.iter_mut().enumerate() {
+            for (x, col) in row.iter_mut().enumerate() {
+                *col = (z as u64).wrapping_mul(0x9E3779B97F4A7C15) ^ (x as u64).rotate_left(7);
+            }
+        }
+    }
+    axis_cols
+}
+
+fn bench_scalar(c: &mut Criterion) {
+    let axis_cols = sample_axis_cols();
+    c.bench_function("cull_faces_scalar", |b| {
+        b.iter(|| {
+            let mut col_face_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
+            expanse_ui::components::voxel2::meshing::greedyMesherOptimized_annotated::cull_faces_scalar(
+                black_box(&axis_cols),
+                &mut col_face_masks,
+            );
+            black_box(col_face_masks);
+        })
+    });
+}
+
+#[cfg(feature = "portable_simd")]
+fn bench_simd(c: &mut Criterion) {
+    let axis_cols = sample_axis_cols();
+    c.bench_function("cull_faces_simd", |b| {
+        b.iter(|| {
+            let mut col_face_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
+            expanse_ui::components::voxel2::meshing::greedyMesherOptimized_annotated::cull_faces_simd(
+                black_box(&axis_cols),
+                &mut col_face_masks,
+            );
+            black_box(col_face_masks);
+        })
+    });
+}
+
+#[cfg(feature = "portable_simd")]
+criterion_group!(benches, bench_scalar, bench_simd);
+#[cfg(not(feature = "portable_simd"))]
+criterion_group!(benches, bench_scalar);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// The geometry `build_chunk_mesh` produces for one chunk.
+///
+/// The blocky mesher (`MeshStyle::Blocky`) packs every vertex into a single
+/// `u32` via `make_vertex_u32` (position/AO/normal-index/block-type all fit in
+/// 32 bits) and writes to `vertices`/`transparent_vertices`, with `tangents`/
+/// `transparent_tangents` populated alongside when tangent emission is
+/// requested. The smooth mesher (`MeshStyle::Smooth`) produces continuous,
+/// non-grid-aligned vertex positions and gradient-derived normals that can't
+/// be packed into that scheme, so it writes the plain `positions`/`normals`
+/// buffers instead. A chunk only ever uses one style, so a renderer checks
+/// which buffer is non-empty to know which attribute layout to build.
+#[derive(Debug, Default, Clone)]
+pub struct ChunkMesh {
+    /// Packed opaque/cutout vertices (`MeshStyle::Blocky` only).
+    pub vertices: Vec<u32>,
+    /// Packed transparent vertices, drawn in a separate alpha pass
+    /// (`MeshStyle::Blocky` only).
+    pub transparent_vertices: Vec<u32>,
+    /// `Mesh::ATTRIBUTE_TANGENT` data parallel to `vertices`, present only
+    /// when tangent emission was requested.
+    pub tangents: Vec<[f32; 4]>,
+    /// `Mesh::ATTRIBUTE_TANGENT` data parallel to `transparent_vertices`.
+    pub transparent_tangents: Vec<[f32; 4]>,
+    /// Triangle indices into `vertices` (`MeshStyle::Blocky`) or into
+    /// `positions` (`MeshStyle::Smooth`).
+    pub indices: Vec<u32>,
+    /// Triangle indices into `transparent_vertices` (`MeshStyle::Blocky`).
+    pub transparent_indices: Vec<u32>,
+    /// Plain vertex positions (`MeshStyle::Smooth` only).
+    pub positions: Vec<[f32; 3]>,
+    /// Plain vertex normals, parallel to `positions` (`MeshStyle::Smooth`
+    /// only).
+    pub normals: Vec<[f32; 3]>,
+}
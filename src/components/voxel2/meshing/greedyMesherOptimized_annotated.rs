@@ -22,9 +22,14 @@ use crate::{
 //
 // 1. SOLID VOXEL ENCODING: Convert the 3D voxel data into binary representations
 //    along each axis (X, Y, Z). Each bit represents whether a voxel is solid.
+//    Opaque voxels (including alpha-tested cutouts like leaves) share one mask;
+//    every transparent block type (glass, water, ...) gets its own mask so
+//    transparent geometry can be culled independently of opaque geometry.
 //
 // 2. FACE CULLING: Use bitwise operations to find visible faces by detecting
-//    transitions between solid and air voxels.
+//    transitions between solid and air voxels. Transparent faces are additionally
+//    culled against same-type neighbors, so adjacent panes of glass don't render
+//    their shared interior face, while glass next to water still does.
 //
 // 3. FACE GROUPING: Group faces by their properties (block type, ambient occlusion)
 //    and organize them into 2D binary planes.
@@ -36,7 +41,37 @@ use crate::{
 //    proper normals, UVs, and ambient occlusion data.
 // ================================================================================
 
-pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh> {
+/// Selects which surface extractor `build_chunk_mesh` runs for a chunk.
+///
+/// `Blocky` is the binary greedy mesher (axis-aligned cube faces, the fast path).
+/// `Smooth` runs marching cubes instead, producing a rounded isosurface, at the
+/// cost of giving up greedy merging since marching-cubes triangles aren't
+/// axis-aligned quads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshStyle {
+    Blocky,
+    Smooth,
+}
+
+/// Builds a chunk's render mesh using the requested `MeshStyle`.
+///
+/// `emit_tangents` additionally fills `ChunkMesh::tangents` /
+/// `transparent_tangents` with `Mesh::ATTRIBUTE_TANGENT` data so block
+/// materials can use normal/parallax maps. It only applies to `Blocky`:
+/// marching-cubes triangles aren't axis-aligned, so `Smooth` ignores it.
+pub fn build_chunk_mesh(
+    chunks_refs: &ChunksRefs,
+    lod: Lod,
+    style: MeshStyle,
+    emit_tangents: bool,
+) -> Option<ChunkMesh> {
+    match style {
+        MeshStyle::Blocky => build_chunk_mesh_blocky(chunks_refs, lod, emit_tangents),
+        MeshStyle::Smooth => build_chunk_mesh_smooth(chunks_refs, lod),
+    }
+}
+
+fn build_chunk_mesh_blocky(chunks_refs: &ChunksRefs, lod: Lod, emit_tangents: bool) -> Option<ChunkMesh> {
     // Early optimization: if the entire chunk is made of the same block type,
     // we can skip mesh generation entirely (either all air or all solid)
     if chunks_refs.is_all_voxels_same() {
@@ -44,26 +79,48 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
     }
     let mut mesh = ChunkMesh::default();
 
+    // How many full-resolution voxels one LOD cell represents along an axis, and
+    // how many LOD cells fit along one chunk axis. At `jump == 1` (the nearest
+    // LOD) every voxel is its own cell and the fast paths below run unchanged;
+    // `jump > 1` routes voxel encoding through the majority-vote downsampling
+    // pre-pass so distant chunks actually greedy-mesh fewer quads instead of
+    // just spacing full-resolution ones further apart.
+    let jump = lod.jump_index() as usize;
+    let lod_size = (CHUNK_SIZE / jump) as u32;
+
     // ================================================================================
     // PHASE 1: BINARY ENCODING OF SOLID VOXELS
     // ================================================================================
-    
-    // These arrays store binary representations of solid voxels along each axis.
+
+    // These arrays store binary representations of *opaque* voxels along each axis.
+    // Cutout blocks (alpha-tested, e.g. leaves) count as opaque here on purpose: they
+    // still fully occlude whatever is behind them, so they should keep greedy merging
+    // just as aggressive as fully solid blocks.
     // For each axis (0=Y, 1=X, 2=Z), we store a 2D array where each u64 represents
     // a column of voxels along that axis. Each bit in the u64 represents whether
-    // a voxel at that position is solid (1) or air (0).
-    // 
+    // a voxel at that position is opaque (1) or not (0).
+    //
     // axis_cols[0][z][x] = column along Y axis at position (x,z)
     // axis_cols[1][y][z] = column along X axis at position (y,z)
     // axis_cols[2][y][x] = column along Z axis at position (x,y)
     let mut axis_cols = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3];
 
+    // True (alpha-blended) transparent voxels, e.g. glass or water, get their own set
+    // of binary columns per block type. They're kept separate from `axis_cols` because
+    // two different transparent types (or a transparent type and air) must NOT cull
+    // each other's faces, while two voxels of the *same* transparent type should.
+    let mut transparent_axis_cols: HashMap<u32, [[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3]> =
+        HashMap::new();
+
     // These arrays store which faces are visible (not culled) for each of the 6 directions.
     // Index mapping: 0=Down(-Y), 1=Up(+Y), 2=Left(-X), 3=Right(+X), 4=Forward(-Z), 5=Back(+Z)
     // A bit is set to 1 if a face at that position should be rendered.
     let mut col_face_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
 
-    // Helper function to set bits in axis_cols based on voxel solidity
+    // Helper function to set bits in axis_cols/transparent_axis_cols based on voxel kind.
+    // Opaque and cutout voxels feed the single opaque mask; each transparent block type
+    // gets its own mask so same-type transparent faces can still be culled against
+    // each other.
     #[inline]
     fn add_voxel_to_axis_cols(
         b: &crate::voxel::BlockData,
@@ -71,20 +128,99 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
         y: usize,
         z: usize,
         axis_cols: &mut [[[u64; 34]; 34]; 3],
+        transparent_axis_cols: &mut HashMap<u32, [[[u64; 34]; 34]; 3]>,
     ) {
-        if b.block_type.is_solid() {
+        if b.block_type.is_transparent() {
+            let cols = transparent_axis_cols
+                .entry(b.block_type as u32)
+                .or_insert([[[0u64; 34]; 34]; 3]);
+            cols[0][z][x] |= 1u64 << y as u64;
+            cols[1][y][z] |= 1u64 << x as u64;
+            cols[2][y][x] |= 1u64 << z as u64;
+        } else if b.block_type.is_solid() {
             // Set the bit at position 'y' in the Y-axis column at (x,z)
-            // This represents that there's a solid voxel at (x,y,z)
+            // This represents that there's an opaque voxel at (x,y,z)
             axis_cols[0][z][x] |= 1u64 << y as u64;
-            
+
             // Set the bit at position 'x' in the X-axis column at (y,z)
             axis_cols[1][y][z] |= 1u64 << x as u64;
-            
+
             // Set the bit at position 'z' in the Z-axis column at (x,y)
             axis_cols[2][y][x] |= 1u64 << z as u64;
         }
     }
 
+    // Picks the block type that appears most often among a set of sampled voxels,
+    // breaking ties in favor of whichever contender is solid so a half-covered LOD
+    // cell erodes toward keeping its silhouette rather than toward air.
+    #[inline]
+    fn majority_vote(samples: impl Iterator<Item = crate::voxel::BlockData>) -> crate::voxel::BlockData {
+        let mut counts: HashMap<u32, (u32, crate::voxel::BlockData)> = HashMap::new();
+        for voxel in samples {
+            let entry = counts
+                .entry(voxel.block_type as u32)
+                .or_insert((0, voxel));
+            entry.0 += 1;
+        }
+        counts
+            .into_values()
+            .max_by(|(count_a, block_a), (count_b, block_b)| {
+                count_a
+                    .cmp(count_b)
+                    .then_with(|| block_a.block_type.is_solid().cmp(&block_b.block_type.is_solid()))
+            })
+            .map(|(_, block)| block)
+            .expect("jump is always >= 1, so at least one voxel was sampled")
+    }
+
+    // Majority-votes the `jump x jump x jump` full-resolution voxels underlying
+    // one coarse LOD cell, indexing the current chunk's own voxel array directly.
+    // Only valid when the whole `jump`-sized block lies inside this chunk, which
+    // holds here since CHUNK_SIZE is always a multiple of `jump`.
+    #[inline]
+    fn lod_majority_vote_local(
+        chunk: &crate::chunks_refs::Chunk,
+        cx: usize,
+        cy: usize,
+        cz: usize,
+        jump: usize,
+    ) -> crate::voxel::BlockData {
+        if chunk.voxels.len() == 1 {
+            return chunk.voxels[0];
+        }
+        majority_vote((0..jump).flat_map(|dz| {
+            (0..jump).flat_map(move |dy| {
+                (0..jump).map(move |dx| {
+                    let x = cx * jump + dx;
+                    let y = cy * jump + dy;
+                    let z = cz * jump + dz;
+                    chunk.voxels[(z * CHUNK_SIZE + y) * CHUNK_SIZE + x]
+                })
+            })
+        }))
+    }
+
+    // Same majority vote, but for an LOD cell that may cross chunk boundaries:
+    // `world_origin` is the chunk-local full-resolution voxel the cell starts at
+    // (possibly negative or >= CHUNK_SIZE), sampled through `chunks_refs.get_block`
+    // so neighbor chunks are reached the same way the rest of this file does.
+    #[inline]
+    fn lod_majority_vote_world(
+        chunks_refs: &ChunksRefs,
+        world_origin: IVec3,
+        jump: i32,
+    ) -> crate::voxel::BlockData {
+        if jump == 1 {
+            return *chunks_refs.get_block(world_origin);
+        }
+        majority_vote((0..jump).flat_map(|dz| {
+            (0..jump).flat_map(move |dy| {
+                (0..jump)
+                    .map(move |dx| *chunks_refs.get_block(world_origin + ivec3(dx, dy, dz)))
+            })
+        }))
+    }
+
     // ================================================================================
     // STEP 1.1: Process inner chunk voxels
     // ================================================================================
@@ -97,18 +233,50 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
     // - Full format: CHUNK_SIZE³ voxels stored individually
     // - Compressed format: 1 voxel repeated for the entire chunk
     assert!(chunk.voxels.len() == CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE || chunk.voxels.len() == 1);
-    
-    // Iterate through all voxels in the chunk
-    for z in 0..CHUNK_SIZE {
-        for y in 0..CHUNK_SIZE {
-            for x in 0..CHUNK_SIZE {
-                // Handle both storage formats
-                let i = match chunk.voxels.len() {
-                    1 => 0, // Compressed: always use the single voxel
-                    _ => (z * CHUNK_SIZE + y) * CHUNK_SIZE + x, // Full: calculate index
-                };
-                // Add 1 to coordinates because axis_cols has padding for neighbor voxels
-                add_voxel_to_axis_cols(&chunk.voxels[i], x + 1, y + 1, z + 1, &mut axis_cols)
+
+    let lod_chunk_size = lod_size as usize;
+
+    if jump == 1 {
+        // Fast path: one voxel is one LOD cell, so index the chunk directly
+        // without going through the majority-vote machinery below.
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    // Handle both storage formats
+                    let i = match chunk.voxels.len() {
+                        1 => 0, // Compressed: always use the single voxel
+                        _ => (z * CHUNK_SIZE + y) * CHUNK_SIZE + x, // Full: calculate index
+                    };
+                    // Add 1 to coordinates because axis_cols has padding for neighbor voxels
+                    add_voxel_to_axis_cols(
+                        &chunk.voxels[i],
+                        x + 1,
+                        y + 1,
+                        z + 1,
+                        &mut axis_cols,
+                        &mut transparent_axis_cols,
+                    )
+                }
+            }
+        }
+    } else {
+        // Downsampled path: each LOD cell covers a `jump x jump x jump` block of
+        // full-resolution voxels entirely inside this chunk (CHUNK_SIZE is always
+        // a multiple of `jump`), so majority-voting can index `chunk.voxels`
+        // directly rather than going through `chunks_refs.get_block`.
+        for cz in 0..lod_chunk_size {
+            for cy in 0..lod_chunk_size {
+                for cx in 0..lod_chunk_size {
+                    let voxel = lod_majority_vote_local(chunk, cx, cy, cz, jump);
+                    add_voxel_to_axis_cols(
+                        &voxel,
+                        cx + 1,
+                        cy + 1,
+                        cz + 1,
+                        &mut axis_cols,
+                        &mut transparent_axis_cols,
+                    )
+                }
             }
         }
     }
@@ -116,37 +284,61 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
     // ================================================================================
     // STEP 1.2: Process neighbor chunk voxels (for proper face culling at boundaries)
     // ================================================================================
-    
+
     // We need to sample voxels from neighboring chunks to properly cull faces
     // at chunk boundaries. Without this, faces between chunks would always be visible.
-    
+    // Each padded coordinate `c` here is an LOD-cell coordinate, so the full-resolution
+    // voxels it covers start at world offset `(c - 1) * jump` (mirroring the `+ 1`
+    // padding offset the inner loop above uses).
+    let lod_grid_size = lod_chunk_size + 2;
+
     // Process Z-axis boundaries (front and back faces of the chunk)
-    for z in [0, CHUNK_SIZE_P - 1] {
-        for y in 0..CHUNK_SIZE_P {
-            for x in 0..CHUNK_SIZE_P {
-                // Convert padded coordinates to world coordinates
-                let pos = ivec3(x as i32, y as i32, z as i32) - IVec3::ONE;
-                add_voxel_to_axis_cols(chunks_refs.get_block(pos), x, y, z, &mut axis_cols);
+    for z in [0, lod_grid_size - 1] {
+        for y in 0..lod_grid_size {
+            for x in 0..lod_grid_size {
+                let world_origin = (ivec3(x as i32, y as i32, z as i32) - IVec3::ONE) * jump as i32;
+                add_voxel_to_axis_cols(
+                    &lod_majority_vote_world(chunks_refs, world_origin, jump as i32),
+                    x,
+                    y,
+                    z,
+                    &mut axis_cols,
+                    &mut transparent_axis_cols,
+                );
             }
         }
     }
-    
+
     // Process Y-axis boundaries (top and bottom faces of the chunk)
-    for z in 0..CHUNK_SIZE_P {
-        for y in [0, CHUNK_SIZE_P - 1] {
-            for x in 0..CHUNK_SIZE_P {
-                let pos = ivec3(x as i32, y as i32, z as i32) - IVec3::ONE;
-                add_voxel_to_axis_cols(chunks_refs.get_block(pos), x, y, z, &mut axis_cols);
+    for z in 0..lod_grid_size {
+        for y in [0, lod_grid_size - 1] {
+            for x in 0..lod_grid_size {
+                let world_origin = (ivec3(x as i32, y as i32, z as i32) - IVec3::ONE) * jump as i32;
+                add_voxel_to_axis_cols(
+                    &lod_majority_vote_world(chunks_refs, world_origin, jump as i32),
+                    x,
+                    y,
+                    z,
+                    &mut axis_cols,
+                    &mut transparent_axis_cols,
+                );
             }
         }
     }
-    
+
     // Process X-axis boundaries (left and right faces of the chunk)
-    for z in 0..CHUNK_SIZE_P {
-        for x in [0, CHUNK_SIZE_P - 1] {
-            for y in 0..CHUNK_SIZE_P {
-                let pos = ivec3(x as i32, y as i32, z as i32) - IVec3::ONE;
-                add_voxel_to_axis_cols(chunks_refs.get_block(pos), x, y, z, &mut axis_cols);
+    for z in 0..lod_grid_size {
+        for x in [0, lod_grid_size - 1] {
+            for y in 0..lod_grid_size {
+                let world_origin = (ivec3(x as i32, y as i32, z as i32) - IVec3::ONE) * jump as i32;
+                add_voxel_to_axis_cols(
+                    &lod_majority_vote_world(chunks_refs, world_origin, jump as i32),
+                    x,
+                    y,
+                    z,
+                    &mut axis_cols,
+                    &mut transparent_axis_cols,
+                );
             }
         }
     }
@@ -154,40 +346,47 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
     // ================================================================================
     // PHASE 2: FACE CULLING USING BITWISE OPERATIONS
     // ================================================================================
-    
+
     // This is the key optimization: we use bitwise operations to detect transitions
-    // between solid and air voxels, which indicate visible faces.
-    for axis in 0..3 {
-        for z in 0..CHUNK_SIZE_P {
-            for x in 0..CHUNK_SIZE_P {
-                // Get the column of solid/air bits for this position
-                let col = axis_cols[axis][z][x];
+    // between opaque and non-opaque voxels, which indicate visible faces. Cutout
+    // blocks are folded into `axis_cols` already, so this pass alone handles both.
+    // Every column is independent of every other column, so this is embarrassingly
+    // parallel across the `CHUNK_SIZE_P * CHUNK_SIZE_P` columns of each axis; with
+    // the `portable_simd` feature enabled we process them 8 lanes at a time instead
+    // of one u64 at a time. `std::simd` is nightly-only, so the crate root needs
+    // `#![cfg_attr(feature = "portable_simd", feature(portable_simd))]` and
+    // Cargo.toml needs a matching `portable_simd = []` feature for this to build.
+    #[cfg(feature = "portable_simd")]
+    cull_faces_simd(&axis_cols, &mut col_face_masks);
+    #[cfg(not(feature = "portable_simd"))]
+    cull_faces_scalar(&axis_cols, &mut col_face_masks);
 
-                // DESCENDING DIRECTION (negative axis direction):
-                // col & !(col << 1) finds transitions from solid to air
-                // Example: col = 0b00111000
-                //          col << 1 = 0b01110000
-                //          !(col << 1) = 0b10001111
-                //          col & !(col << 1) = 0b00001000
-                // This gives us a 1 where a solid voxel has air below it
-                col_face_masks[2 * axis + 0][z][x] = col & !(col << 1);
-                
-                // ASCENDING DIRECTION (positive axis direction):
-                // col & !(col >> 1) finds transitions from air to solid
-                // Example: col = 0b00111000
-                //          col >> 1 = 0b00011100
-                //          !(col >> 1) = 0b11100011
-                //          col & !(col >> 1) = 0b00100000
-                // This gives us a 1 where a solid voxel has air above it
-                col_face_masks[2 * axis + 1][z][x] = col & !(col >> 1);
+    // For every transparent block type, cull its faces against `opaque | same_type`:
+    // a transparent face stays hidden behind opaque geometry or an identical
+    // transparent neighbor, but stays visible against a *different* transparent type
+    // or against air, which is exactly what lets glass-next-to-water render correctly.
+    let mut transparent_col_face_masks: HashMap<u32, [[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6]> =
+        HashMap::new();
+    for (&block_type, cols) in transparent_axis_cols.iter() {
+        let mut face_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
+        for axis in 0..3 {
+            for z in 0..CHUNK_SIZE_P {
+                for x in 0..CHUNK_SIZE_P {
+                    let same_type = cols[axis][z][x];
+                    let opaque = axis_cols[axis][z][x];
+                    let (descending, ascending) = transparent_face_masks(same_type, opaque);
+                    face_masks[2 * axis + 0][z][x] = descending;
+                    face_masks[2 * axis + 1][z][x] = ascending;
+                }
             }
         }
+        transparent_col_face_masks.insert(block_type, face_masks);
     }
 
     // ================================================================================
     // PHASE 3: GROUP FACES BY PROPERTIES AND BUILD 2D PLANES
     // ================================================================================
-    
+
     // We group faces by their properties to enable greedy meshing.
     // Faces can only be merged if they have:
     // - Same block type
@@ -196,149 +395,206 @@ pub fn build_chunk_mesh(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh>
     //
     // Structure: data[axis][block_hash][y_level] = 32x32 binary plane
     // where block_hash encodes both block type and AO pattern
-    let mut data: [HashMap<u32, HashMap<u32, [u32; 32]>>; 6];
-    data = [
-        HashMap::new(),
-        HashMap::new(),
-        HashMap::new(),
-        HashMap::new(),
-        HashMap::new(),
-        HashMap::new(),
-    ];
-
-    // Process each axis direction separately
-    for axis in 0..6 {
-        for z in 0..CHUNK_SIZE {
-            for x in 0..CHUNK_SIZE {
-                // Get the column of face bits for this position
-                // Add 1 to skip padding
-                let mut col = col_face_masks[axis][z + 1][x + 1];
-
-                // Remove padding bits:
-                // Right shift by 1 to remove bottom padding
-                col >>= 1;
-                // Clear the top bit to remove top padding
-                col &= !(1 << CHUNK_SIZE as u64);
-
-                // Process each set bit (visible face) in the column
-                while col != 0 {
-                    // Find the position of the lowest set bit
-                    let y = col.trailing_zeros();
-                    // Clear the lowest set bit for next iteration
-                    // This is the Brian Kernighan algorithm for bit manipulation
-                    col &= col - 1;
-
-                    // Convert axis-relative coordinates to world coordinates
-                    // The mapping depends on which face direction we're processing
-                    let voxel_pos = match axis {
-                        0 | 1 => ivec3(x as i32, y as i32, z as i32), // Y-axis (down/up)
-                        2 | 3 => ivec3(y as i32, z as i32, x as i32), // X-axis (left/right)
-                        _ => ivec3(x as i32, z as i32, y as i32),     // Z-axis (forward/back)
-                    };
+    //
+    // This grouping logic is shared between the opaque pass and every transparent
+    // pass, so it's pulled out into its own closure over `chunks_refs` rather than
+    // duplicated per mask set.
+    let group_faces_by_plane = |col_face_masks: &[[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6]| {
+        let mut data: [HashMap<u32, HashMap<u32, [u32; 32]>>; 6] = [
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+        ];
+
+        // Process each axis direction separately
+        for axis in 0..6 {
+            for z in 0..lod_chunk_size {
+                for x in 0..lod_chunk_size {
+                    // Get the column of face bits for this position
+                    // Add 1 to skip padding
+                    let mut col = col_face_masks[axis][z + 1][x + 1];
+
+                    // Remove padding bits:
+                    // Right shift by 1 to remove bottom padding
+                    col >>= 1;
+                    // Clear the top bit to remove top padding
+                    col &= !(1u64 << lod_chunk_size as u64);
+
+                    // Process each set bit (visible face) in the column
+                    while col != 0 {
+                        // Find the position of the lowest set bit
+                        let y = col.trailing_zeros();
+                        // Clear the lowest set bit for next iteration
+                        // This is the Brian Kernighan algorithm for bit manipulation
+                        col &= col - 1;
+
+                        // Convert axis-relative LOD-cell coordinates to world coordinates.
+                        // The mapping depends on which face direction we're processing; the
+                        // `* jump` scales the LOD-cell coordinate back to the full-resolution
+                        // voxel grid `chunks_refs` samples against.
+                        let voxel_pos = match axis {
+                            0 | 1 => ivec3(x as i32, y as i32, z as i32), // Y-axis (down/up)
+                            2 | 3 => ivec3(y as i32, z as i32, x as i32), // X-axis (left/right)
+                            _ => ivec3(x as i32, z as i32, y as i32),     // Z-axis (forward/back)
+                        } * jump as i32;
+
+                        // ================================================================================
+                        // AMBIENT OCCLUSION CALCULATION
+                        // ================================================================================
+
+                        // Ambient occlusion (AO) darkens corners where multiple opaque blocks meet.
+                        // We sample 8 positions around each face vertex to determine AO strength.
+                        // The bit pattern encodes which of the 8 surrounding positions are opaque.
+                        let mut ao_index = 0;
+
+                        // ADJACENT_AO_DIRS contains 8 2D offsets for sampling around a face
+                        for (ao_i, ao_offset) in ADJACENT_AO_DIRS.iter().enumerate() {
+                            // Convert 2D face offset to 3D world offset based on face direction
+                            let ao_sample_offset = match axis {
+                                0 => ivec3(ao_offset.x, -1, ao_offset.y), // Down face
+                                1 => ivec3(ao_offset.x, 1, ao_offset.y),  // Up face
+                                2 => ivec3(-1, ao_offset.y, ao_offset.x), // Left face
+                                3 => ivec3(1, ao_offset.y, ao_offset.x),  // Right face
+                                4 => ivec3(ao_offset.x, ao_offset.y, -1), // Forward face
+                                _ => ivec3(ao_offset.x, ao_offset.y, 1),  // Back face
+                            };
 
-                    // ================================================================================
-                    // AMBIENT OCCLUSION CALCULATION
-                    // ================================================================================
-                    
-                    // Ambient occlusion (AO) darkens corners where multiple solid blocks meet.
-                    // We sample 8 positions around each face vertex to determine AO strength.
-                    // The bit pattern encodes which of the 8 surrounding positions have solid blocks.
-                    let mut ao_index = 0;
-                    
-                    // ADJACENT_AO_DIRS contains 8 2D offsets for sampling around a face
-                    for (ao_i, ao_offset) in ADJACENT_AO_DIRS.iter().enumerate() {
-                        // Convert 2D face offset to 3D world offset based on face direction
-                        let ao_sample_offset = match axis {
-                            0 => ivec3(ao_offset.x, -1, ao_offset.y), // Down face
-                            1 => ivec3(ao_offset.x, 1, ao_offset.y),  // Up face
-                            2 => ivec3(-1, ao_offset.y, ao_offset.x), // Left face
-                            3 => ivec3(1, ao_offset.y, ao_offset.x),  // Right face
-                            4 => ivec3(ao_offset.x, ao_offset.y, -1), // Forward face
-                            _ => ivec3(ao_offset.x, ao_offset.y, 1),  // Back face
-                        };
-                        
-                        // Sample the voxel at the AO position
-                        let ao_voxel_pos = voxel_pos + ao_sample_offset;
-                        let ao_block = chunks_refs.get_block(ao_voxel_pos);
-                        
-                        // Set bit if position has a solid block
-                        if ao_block.block_type.is_solid() {
-                            ao_index |= 1u32 << ao_i;
+                            // Sample the voxel at the AO position, one LOD cell (`jump` voxels)
+                            // over in the offset direction
+                            let ao_voxel_pos = voxel_pos + ao_sample_offset * jump as i32;
+                            let ao_block = chunks_refs.get_block(ao_voxel_pos);
+
+                            // Set bit if position has an opaque block (transparent neighbors
+                            // shouldn't darken corners the way opaque geometry does)
+                            if ao_block.block_type.is_solid() && !ao_block.block_type.is_transparent()
+                            {
+                                ao_index |= 1u32 << ao_i;
+                            }
                         }
-                    }
 
-                    // Get the block type of the current voxel
-                    let current_voxel = chunks_refs.get_block_no_neighbour(voxel_pos);
-                    
-                    // Create a hash that combines block type and AO pattern
-                    // Lower 9 bits: AO pattern (8 bits + 1 reserved)
-                    // Upper bits: Block type ID
-                    let block_hash = ao_index | ((current_voxel.block_type as u32) << 9);
-                    
-                    // Store this face in the appropriate 2D binary plane
-                    // Faces with the same block_hash can be greedy meshed together
-                    let data = data[axis]
-                        .entry(block_hash)
-                        .or_default()
-                        .entry(y)
-                        .or_default();
-                    
-                    // Set the bit corresponding to this face's position in the 2D plane
-                    data[x as usize] |= 1u32 << z as u32;
+                        // Get the block type of the current voxel
+                        let current_voxel = chunks_refs.get_block_no_neighbour(voxel_pos);
+
+                        // Create a hash that combines block type and AO pattern
+                        // Lower 9 bits: AO pattern (8 bits + 1 reserved)
+                        // Upper bits: Block type ID
+                        let block_hash = ao_index | ((current_voxel.block_type as u32) << 9);
+
+                        // Store this face in the appropriate 2D binary plane
+                        // Faces with the same block_hash can be greedy meshed together
+                        let data = data[axis]
+                            .entry(block_hash)
+                            .or_default()
+                            .entry(y)
+                            .or_default();
+
+                        // Set the bit corresponding to this face's position in the 2D plane
+                        data[x as usize] |= 1u32 << z as u32;
+                    }
                 }
             }
         }
-    }
+
+        data
+    };
+
+    let data = group_faces_by_plane(&col_face_masks);
 
     // ================================================================================
     // PHASE 4: GREEDY MESHING AND VERTEX GENERATION
     // ================================================================================
-    
-    let mut vertices = vec![];
-    
-    // Process each axis direction
-    for (axis, block_ao_data) in data.into_iter().enumerate() {
-        // Convert axis index to face direction enum
-        let facedir = match axis {
-            0 => FaceDir::Down,
-            1 => FaceDir::Up,
-            2 => FaceDir::Left,
-            3 => FaceDir::Right,
-            4 => FaceDir::Forward,
-            _ => FaceDir::Back,
-        };
-        
-        // Process each unique block type + AO combination
-        for (block_ao, axis_plane) in block_ao_data.into_iter() {
-            // Extract AO pattern and block type from the hash
-            let ao = block_ao & 0b111111111; // Lower 9 bits
-            let block_type = block_ao >> 9;   // Upper bits
-            
-            // Process each Y-level (or equivalent axis level) separately
-            for (axis_pos, plane) in axis_plane.into_iter() {
-                // Run the greedy meshing algorithm on this 2D binary plane
-                let quads_from_axis = greedy_mesh_binary_plane(plane, lod_size as u32);
-
-                // Convert each greedy quad to actual mesh vertices
-                quads_from_axis.into_iter().for_each(|q| {
-                    q.append_vertices(&mut vertices, facedir, axis_pos, &Lod::L32, ao, block_type)
-                });
+
+    // Greedy-meshes a grouped `data` set (one opaque or transparent mask set worth)
+    // into packed vertices. Shared between the opaque pass and every transparent
+    // pass below since the merging logic doesn't care which mask set produced it.
+    // `tangents_out` is only `Some` when `emit_tangents` is set, in which case one
+    // `Mesh::ATTRIBUTE_TANGENT` entry is appended per vertex pushed to `out`.
+    let emit_quads_for_axes = |data: [HashMap<u32, HashMap<u32, [u32; 32]>>; 6],
+                                out: &mut Vec<u32>,
+                                mut tangents_out: Option<&mut Vec<[f32; 4]>>| {
+        for (axis, block_ao_data) in data.into_iter().enumerate() {
+            // Convert axis index to face direction enum
+            let facedir = match axis {
+                0 => FaceDir::Down,
+                1 => FaceDir::Up,
+                2 => FaceDir::Left,
+                3 => FaceDir::Right,
+                4 => FaceDir::Forward,
+                _ => FaceDir::Back,
+            };
+
+            // Process each unique block type + AO combination
+            for (block_ao, axis_plane) in block_ao_data.into_iter() {
+                // Extract AO pattern and block type from the hash
+                let ao = block_ao & 0b111111111; // Lower 9 bits
+                let block_type = block_ao >> 9; // Upper bits
+
+                // Process each Y-level (or equivalent axis level) separately
+                for (axis_pos, plane) in axis_plane.into_iter() {
+                    // Run the greedy meshing algorithm on this 2D binary plane
+                    let quads_from_axis = greedy_mesh_binary_plane(plane, lod_size as u32);
+
+                    // Convert each greedy quad to actual mesh vertices
+                    quads_from_axis.into_iter().for_each(|q| {
+                        q.append_vertices(
+                            out,
+                            facedir,
+                            axis_pos,
+                            &lod,
+                            ao,
+                            block_type,
+                            tangents_out.as_deref_mut(),
+                        )
+                    });
+                }
             }
         }
+    };
+
+    let mut vertices = vec![];
+    let mut tangents = emit_tangents.then(Vec::new);
+    emit_quads_for_axes(data, &mut vertices, tangents.as_mut());
+
+    // Each transparent block type greedy-meshes independently of the others (and of
+    // the opaque pass) so its quads land in the renderer's alpha pass instead of
+    // getting merged across type boundaries.
+    let mut transparent_vertices = vec![];
+    let mut transparent_tangents = emit_tangents.then(Vec::new);
+    for (_block_type, face_masks) in transparent_col_face_masks.into_iter() {
+        let transparent_data = group_faces_by_plane(&face_masks);
+        emit_quads_for_axes(
+            transparent_data,
+            &mut transparent_vertices,
+            transparent_tangents.as_mut(),
+        );
     }
 
     // ================================================================================
     // PHASE 5: FINALIZE MESH
     // ================================================================================
-    
+
     mesh.vertices.extend(vertices);
-    
-    // If no vertices were generated, return None (chunk is empty or fully culled)
-    if mesh.vertices.is_empty() {
+    // Transparent quads go in their own buffer so the renderer can draw them in a
+    // second, back-to-front, non-depth-writing alpha pass after all opaque geometry.
+    mesh.transparent_vertices.extend(transparent_vertices);
+    if let Some(tangents) = tangents {
+        mesh.tangents.extend(tangents);
+    }
+    if let Some(transparent_tangents) = transparent_tangents {
+        mesh.transparent_tangents.extend(transparent_tangents);
+    }
+
+    // If no vertices were generated at all (opaque or transparent), the chunk is
+    // empty or fully culled
+    if mesh.vertices.is_empty() && mesh.transparent_vertices.is_empty() {
         None
     } else {
-        // Generate indices for the vertices (standard quad indices)
+        // Generate indices for both vertex buffers (standard quad indices)
         mesh.indices = generate_indices(mesh.vertices.len());
+        mesh.transparent_indices = generate_indices(mesh.transparent_vertices.len());
         Some(mesh)
     }
 }
@@ -353,7 +609,11 @@ pub struct GreedyQuad {
 }
 
 impl GreedyQuad {
-    /// Converts this greedy quad into 4 vertices and appends them to the vertex buffer
+    /// Converts this greedy quad into 4 vertices and appends them to the vertex buffer.
+    ///
+    /// `tangents` is the optional extended-vertex output: when `Some`, one
+    /// `Mesh::ATTRIBUTE_TANGENT` value is pushed per vertex this call appends to
+    /// `vertices`, so the two buffers stay index-aligned for the caller.
     pub fn append_vertices(
         &self,
         vertices: &mut Vec<u32>,
@@ -362,6 +622,7 @@ impl GreedyQuad {
         lod: &Lod,
         ao: u32,
         block_type: u32,
+        tangents: Option<&mut Vec<[f32; 4]>>,
     ) {
         let axis = axis as i32;
         let jump = lod.jump_index();
@@ -465,6 +726,125 @@ impl GreedyQuad {
         }
 
         vertices.extend(new_vertices);
+
+        // Every corner of an axis-aligned greedy quad shares the same tangent, so
+        // the extended-vertex mode just pushes it once per vertex just emitted.
+        if let Some(tangents) = tangents {
+            let t = face_tangent(face_dir);
+            tangents.extend([[t.x, t.y, t.z, t.w]; 4]);
+        }
+    }
+}
+
+/// Per-face tangent for normal-mapped block textures.
+///
+/// Every greedy quad is axis-aligned, so the tangent (the model-space
+/// direction the texture's +U axis points) only depends on `FaceDir`, not on
+/// the quad's position, width, or height: it's the world axis the quad's `w`
+/// span runs along. The `w` component is the standard glTF/Bevy handedness
+/// sign (the real bitangent is `cross(normal, tangent.xyz) * tangent.w`);
+/// faces whose winding order is reversed (`FaceDir::reverse_order`) need the
+/// sign flipped too, so the bitangent keeps pointing the way the quad's `h`
+/// span grows in world space rather than being mirrored.
+fn face_tangent(face_dir: FaceDir) -> Vec4 {
+    let (tangent, handedness) = match face_dir {
+        FaceDir::Up => (Vec3::X, 1.0),
+        FaceDir::Down => (Vec3::X, -1.0),
+        FaceDir::Right => (Vec3::Z, -1.0),
+        FaceDir::Left => (Vec3::Z, 1.0),
+        FaceDir::Back => (Vec3::X, 1.0),
+        FaceDir::Forward => (Vec3::X, -1.0),
+    };
+    let handedness = if face_dir.reverse_order() {
+        -handedness
+    } else {
+        handedness
+    };
+    Vec4::new(tangent.x, tangent.y, tangent.z, handedness)
+}
+
+/// Culls one transparent-type column's faces against `opaque | same_type`: a
+/// transparent face stays hidden where the neighbor is opaque or an identical
+/// transparent block, but stays visible where a *different* transparent type
+/// or air meets it, which is what lets e.g. glass-next-to-water render
+/// correctly instead of disappearing like it would against its own type.
+/// Returns the `(descending, ascending)` transition masks, mirroring
+/// `cull_faces_scalar`'s per-direction pair.
+#[inline]
+fn transparent_face_masks(same_type: u64, opaque: u64) -> (u64, u64) {
+    let occluders = opaque | same_type;
+    let descending = same_type & !(occluders << 1);
+    let ascending = same_type & !(occluders >> 1);
+    (descending, ascending)
+}
+
+// ================================================================================
+// PHASE 2 KERNEL: SCALAR AND SIMD FACE CULLING
+// ================================================================================
+
+/// Scalar fallback for Phase 2 face culling: the shift-and-andnot transition
+/// detection described above, applied one `u64` column at a time. Kept compiled
+/// regardless of the `portable_simd` feature so the benchmark can compare it
+/// against `cull_faces_simd` directly.
+pub fn cull_faces_scalar(
+    axis_cols: &[[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3],
+    col_face_masks: &mut [[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6],
+) {
+    for axis in 0..3 {
+        for z in 0..CHUNK_SIZE_P {
+            for x in 0..CHUNK_SIZE_P {
+                let col = axis_cols[axis][z][x];
+                col_face_masks[2 * axis + 0][z][x] = col & !(col << 1);
+                col_face_masks[2 * axis + 1][z][x] = col & !(col >> 1);
+            }
+        }
+    }
+}
+
+/// SIMD Phase 2 face culling: loads 8 contiguous columns (`axis_cols[axis][z]`
+/// is already a contiguous `[u64; CHUNK_SIZE_P]` row) into a `u64x8` register at a
+/// time and applies the same shift-and-andnot transition detection to all 8 lanes
+/// at once. Each lane shifts independently, so this produces bit-for-bit the same
+/// result as `cull_faces_scalar` for every column, just processed 8-wide. The
+/// `CHUNK_SIZE_P` row length (34) isn't a multiple of 8, so the remaining columns
+/// in each row fall back to the scalar path.
+#[cfg(feature = "portable_simd")]
+pub fn cull_faces_simd(
+    axis_cols: &[[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3],
+    col_face_masks: &mut [[[u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6],
+) {
+    use std::simd::{u64x8, Simd};
+
+    const LANES: usize = 8;
+
+    for axis in 0..3 {
+        for z in 0..CHUNK_SIZE_P {
+            let row = &axis_cols[axis][z];
+            let mut x = 0;
+
+            while x + LANES <= CHUNK_SIZE_P {
+                let cols = u64x8::from_slice(&row[x..x + LANES]);
+
+                let shifted_up = cols << Simd::splat(1);
+                let descending = cols & !shifted_up;
+
+                let shifted_down = cols >> Simd::splat(1);
+                let ascending = cols & !shifted_down;
+
+                descending.copy_to_slice(&mut col_face_masks[2 * axis + 0][z][x..x + LANES]);
+                ascending.copy_to_slice(&mut col_face_masks[2 * axis + 1][z][x..x + LANES]);
+
+                x += LANES;
+            }
+
+            // Scalar tail for the columns that don't fill a full lane
+            while x < CHUNK_SIZE_P {
+                let col = row[x];
+                col_face_masks[2 * axis + 0][z][x] = col & !(col << 1);
+                col_face_masks[2 * axis + 1][z][x] = col & !(col >> 1);
+                x += 1;
+            }
+        }
     }
 }
 
@@ -540,4 +920,590 @@ pub fn greedy_mesh_binary_plane(mut data: [u32; 32], lod_size: u32) -> Vec<Greed
     }
     
     greedy_quads
-}
\ No newline at end of file
+}
+// ================================================================================
+// MARCHING CUBES SMOOTH-TERRAIN SURFACE EXTRACTOR
+// ================================================================================
+//
+// Classic marching cubes, run as an alternative to the blocky greedy mesher above.
+// Each cell of the (unpadded) CHUNK_SIZE grid samples density at its 8 corners,
+// builds an 8-bit cube index from which corners are below the isolevel, and
+// looks up which of the 12 cube edges cross the surface (MC_EDGE_TABLE) and how
+// to triangulate them (MC_TRIANGLE_TABLE). Vertex positions are placed by lerping
+// along each active edge toward the isolevel crossing; normals come from the
+// density field's gradient (central differences) rather than a packed face index,
+// since marching-cubes faces aren't axis-aligned.
+
+const ISO_LEVEL: f32 = 0.5;
+
+/// Scalar density field sampled at a voxel: 1.0 inside solid geometry, 0.0 in air.
+/// `BlockData` doesn't carry a continuous density value, so solidity is used as a
+/// step function; this is enough to place the isosurface exactly at block faces
+/// while still letting the gradient give rounded-looking normals.
+#[inline]
+fn mc_density(b: &crate::voxel::BlockData) -> f32 {
+    if b.block_type.is_solid() {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Offsets (in voxels) of the 8 corners of a marching-cubes cell, in the same
+/// corner numbering the edge/triangle tables below were generated against.
+const MC_CORNER_OFFSETS: [IVec3; 8] = [
+    IVec3::new(0, 0, 0),
+    IVec3::new(1, 0, 0),
+    IVec3::new(1, 1, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(1, 0, 1),
+    IVec3::new(1, 1, 1),
+    IVec3::new(0, 1, 1),
+];
+
+/// The two corner indices each of the 12 cube edges connects.
+const MC_EDGE_CORNERS: [(usize, usize); 12] = [
+    (0, 1),
+    (1, 2),
+    (2, 3),
+    (3, 0),
+    (4, 5),
+    (5, 6),
+    (6, 7),
+    (7, 4),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+
+/// Packs a cell's 8 corner densities into the cube index `MC_EDGE_TABLE`/
+/// `MC_TRIANGLE_TABLE` are indexed by. Those are the classic Bourke tables,
+/// generated against the convention that a corner *below* the isolevel sets
+/// its bit (not above it) — getting this backwards doesn't just flip winding,
+/// it changes which diagonal ambiguous saddle configurations triangulate
+/// with, since the complement index isn't always a mirror of the original.
+#[inline]
+fn mc_cube_index(corner_density: &[f32; 8]) -> u8 {
+    let mut cube_index: u8 = 0;
+    for (i, d) in corner_density.iter().enumerate() {
+        if *d < ISO_LEVEL {
+            cube_index |= 1 << i;
+        }
+    }
+    cube_index
+}
+
+/// Central-difference gradient of the density field at an integer cell-corner
+/// position, negated and normalized to give an outward-facing surface normal.
+#[inline]
+fn mc_gradient(chunks_refs: &ChunksRefs, p: IVec3) -> Vec3 {
+    let dx = mc_density(chunks_refs.get_block(p + IVec3::X)) - mc_density(chunks_refs.get_block(p - IVec3::X));
+    let dy = mc_density(chunks_refs.get_block(p + IVec3::Y)) - mc_density(chunks_refs.get_block(p - IVec3::Y));
+    let dz = mc_density(chunks_refs.get_block(p + IVec3::Z)) - mc_density(chunks_refs.get_block(p - IVec3::Z));
+    (-Vec3::new(dx, dy, dz)).normalize_or_zero()
+}
+
+fn build_chunk_mesh_smooth(chunks_refs: &ChunksRefs, lod: Lod) -> Option<ChunkMesh> {
+    // Same short-circuit as the blocky path: a uniform chunk has no isosurface
+    // crossing through it at all.
+    if chunks_refs.is_all_voxels_same() {
+        return None;
+    }
+    let mut mesh = ChunkMesh::default();
+
+    let mut positions: Vec<Vec3> = vec![];
+    let mut normals: Vec<Vec3> = vec![];
+
+    let jump = lod.jump_index() as f32;
+
+    // Walk every cell in the (unpadded) chunk, sampling one voxel of border on
+    // each side through `chunks_refs.get_block` so cells flush against a chunk
+    // boundary still get correct neighbor corners and stitch seamlessly with
+    // whatever the adjacent chunk generates.
+    for z in 0..CHUNK_SIZE as i32 {
+        for y in 0..CHUNK_SIZE as i32 {
+            for x in 0..CHUNK_SIZE as i32 {
+                let cell_pos = ivec3(x, y, z);
+
+                let mut corner_density = [0.0f32; 8];
+                for (i, offset) in MC_CORNER_OFFSETS.iter().enumerate() {
+                    corner_density[i] = mc_density(chunks_refs.get_block(cell_pos + *offset));
+                }
+
+                let cube_index = mc_cube_index(&corner_density);
+
+                // Fully inside or fully outside the isosurface: no triangles cross
+                // this cell.
+                let active_edges = MC_EDGE_TABLE[cube_index as usize];
+                if active_edges == 0 {
+                    continue;
+                }
+
+                // Place a vertex (and its gradient-derived normal) on every edge
+                // the surface actually crosses, by lerping between the two
+                // corners' positions/gradients toward the isolevel crossing.
+                let mut edge_vertex = [Vec3::ZERO; 12];
+                let mut edge_normal = [Vec3::ZERO; 12];
+                for (e, &(a, b)) in MC_EDGE_CORNERS.iter().enumerate() {
+                    if active_edges & (1 << e) == 0 {
+                        continue;
+                    }
+                    let da = corner_density[a];
+                    let db = corner_density[b];
+                    let t = if (db - da).abs() > f32::EPSILON {
+                        ((ISO_LEVEL - da) / (db - da)).clamp(0.0, 1.0)
+                    } else {
+                        0.5
+                    };
+                    let pa = cell_pos + MC_CORNER_OFFSETS[a];
+                    let pb = cell_pos + MC_CORNER_OFFSETS[b];
+                    edge_vertex[e] = pa.as_vec3().lerp(pb.as_vec3(), t) * jump;
+                    edge_normal[e] = mc_gradient(chunks_refs, pa).lerp(mc_gradient(chunks_refs, pb), t);
+                }
+
+                let triangles = &MC_TRIANGLE_TABLE[cube_index as usize];
+                let mut i = 0;
+                while i < triangles.len() && triangles[i] >= 0 {
+                    for &e in &triangles[i..i + 3] {
+                        positions.push(edge_vertex[e as usize]);
+                        normals.push(edge_normal[e as usize].normalize_or_zero());
+                    }
+                    i += 3;
+                }
+            }
+        }
+    }
+
+    if positions.is_empty() {
+        None
+    } else {
+        mesh.positions = positions.into_iter().map(|p| p.to_array()).collect();
+        mesh.normals = normals.into_iter().map(|n| n.to_array()).collect();
+        mesh.indices = (0..mesh.positions.len() as u32).collect();
+        Some(mesh)
+    }
+}
+
+#[rustfmt::skip]
+const MC_EDGE_TABLE: [u16; 256] = [
+    0x000, 0x109, 0x203, 0x30a, 0x406, 0x50f, 0x605, 0x70c,
+    0x80c, 0x905, 0xa0f, 0xb06, 0xc0a, 0xd03, 0xe09, 0xf00,
+    0x190, 0x099, 0x393, 0x29a, 0x596, 0x49f, 0x795, 0x69c,
+    0x99c, 0x895, 0xb9f, 0xa96, 0xd9a, 0xc93, 0xf99, 0xe90,
+    0x230, 0x339, 0x033, 0x13a, 0x636, 0x73f, 0x435, 0x53c,
+    0xa3c, 0xb35, 0x83f, 0x936, 0xe3a, 0xf33, 0xc39, 0xd30,
+    0x3a0, 0x2a9, 0x1a3, 0x0aa, 0x7a6, 0x6af, 0x5a5, 0x4ac,
+    0xbac, 0xaa5, 0x9af, 0x8a6, 0xfaa, 0xea3, 0xda9, 0xca0,
+    0x460, 0x569, 0x663, 0x76a, 0x066, 0x16f, 0x265, 0x36c,
+    0xc6c, 0xd65, 0xe6f, 0xf66, 0x86a, 0x963, 0xa69, 0xb60,
+    0x5f0, 0x4f9, 0x7f3, 0x6fa, 0x1f6, 0x0ff, 0x3f5, 0x2fc,
+    0xdfc, 0xcf5, 0xfff, 0xef6, 0x9fa, 0x8f3, 0xbf9, 0xaf0,
+    0x650, 0x759, 0x453, 0x55a, 0x256, 0x35f, 0x055, 0x15c,
+    0xe5c, 0xf55, 0xc5f, 0xd56, 0xa5a, 0xb53, 0x859, 0x950,
+    0x7c0, 0x6c9, 0x5c3, 0x4ca, 0x3c6, 0x2cf, 0x1c5, 0x0cc,
+    0xfcc, 0xec5, 0xdcf, 0xcc6, 0xbca, 0xac3, 0x9c9, 0x8c0,
+    0x8c0, 0x9c9, 0xac3, 0xbca, 0xcc6, 0xdcf, 0xec5, 0xfcc,
+    0x0cc, 0x1c5, 0x2cf, 0x3c6, 0x4ca, 0x5c3, 0x6c9, 0x7c0,
+    0x950, 0x859, 0xb53, 0xa5a, 0xd56, 0xc5f, 0xf55, 0xe5c,
+    0x15c, 0x055, 0x35f, 0x256, 0x55a, 0x453, 0x759, 0x650,
+    0xaf0, 0xbf9, 0x8f3, 0x9fa, 0xef6, 0xfff, 0xcf5, 0xdfc,
+    0x2fc, 0x3f5, 0x0ff, 0x1f6, 0x6fa, 0x7f3, 0x4f9, 0x5f0,
+    0xb60, 0xa69, 0x963, 0x86a, 0xf66, 0xe6f, 0xd65, 0xc6c,
+    0x36c, 0x265, 0x16f, 0x066, 0x76a, 0x663, 0x569, 0x460,
+    0xca0, 0xda9, 0xea3, 0xfaa, 0x8a6, 0x9af, 0xaa5, 0xbac,
+    0x4ac, 0x5a5, 0x6af, 0x7a6, 0x0aa, 0x1a3, 0x2a9, 0x3a0,
+    0xd30, 0xc39, 0xf33, 0xe3a, 0x936, 0x83f, 0xb35, 0xa3c,
+    0x53c, 0x435, 0x73f, 0x636, 0x13a, 0x033, 0x339, 0x230,
+    0xe90, 0xf99, 0xc93, 0xd9a, 0xa96, 0xb9f, 0x895, 0x99c,
+    0x69c, 0x795, 0x49f, 0x596, 0x29a, 0x393, 0x099, 0x190,
+    0xf00, 0xe09, 0xd03, 0xc0a, 0xb06, 0xa0f, 0x905, 0x80c,
+    0x70c, 0x605, 0x50f, 0x406, 0x30a, 0x203, 0x109, 0x000,
+];
+
+#[rustfmt::skip]
+const MC_TRIANGLE_TABLE: [[i8; 16]; 256] = [
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 8, 3, 9, 8, 1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, 1, 2, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 2, 10, 0, 2, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [2, 8, 3, 2, 10, 8, 10, 9, 8, -1, -1, -1, -1, -1, -1, -1],
+    [3, 11, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 11, 2, 8, 11, 0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 9, 0, 2, 3, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 11, 2, 1, 9, 11, 9, 8, 11, -1, -1, -1, -1, -1, -1, -1],
+    [3, 10, 1, 11, 10, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 10, 1, 0, 8, 10, 8, 11, 10, -1, -1, -1, -1, -1, -1, -1],
+    [3, 9, 0, 3, 11, 9, 11, 10, 9, -1, -1, -1, -1, -1, -1, -1],
+    [9, 8, 10, 10, 8, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 3, 0, 7, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 1, 9, 8, 4, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 1, 9, 4, 7, 1, 7, 3, 1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, 8, 4, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 4, 7, 3, 0, 4, 1, 2, 10, -1, -1, -1, -1, -1, -1, -1],
+    [9, 2, 10, 9, 0, 2, 8, 4, 7, -1, -1, -1, -1, -1, -1, -1],
+    [2, 10, 9, 2, 9, 7, 2, 7, 3, 7, 9, 4, -1, -1, -1, -1],
+    [8, 4, 7, 3, 11, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [11, 4, 7, 11, 2, 4, 2, 0, 4, -1, -1, -1, -1, -1, -1, -1],
+    [9, 0, 1, 8, 4, 7, 2, 3, 11, -1, -1, -1, -1, -1, -1, -1],
+    [4, 7, 11, 9, 4, 11, 9, 11, 2, 9, 2, 1, -1, -1, -1, -1],
+    [3, 10, 1, 3, 11, 10, 7, 8, 4, -1, -1, -1, -1, -1, -1, -1],
+    [1, 11, 10, 1, 4, 11, 1, 0, 4, 7, 11, 4, -1, -1, -1, -1],
+    [4, 7, 8, 9, 0, 11, 9, 11, 10, 11, 0, 3, -1, -1, -1, -1],
+    [4, 7, 11, 4, 11, 9, 9, 11, 10, -1, -1, -1, -1, -1, -1, -1],
+    [9, 5, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 5, 4, 0, 8, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 5, 4, 1, 5, 0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [8, 5, 4, 8, 3, 5, 3, 1, 5, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, 9, 5, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 0, 8, 1, 2, 10, 4, 9, 5, -1, -1, -1, -1, -1, -1, -1],
+    [5, 2, 10, 5, 4, 2, 4, 0, 2, -1, -1, -1, -1, -1, -1, -1],
+    [2, 10, 5, 3, 2, 5, 3, 5, 4, 3, 4, 8, -1, -1, -1, -1],
+    [9, 5, 4, 2, 3, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 11, 2, 0, 8, 11, 4, 9, 5, -1, -1, -1, -1, -1, -1, -1],
+    [0, 5, 4, 0, 1, 5, 2, 3, 11, -1, -1, -1, -1, -1, -1, -1],
+    [2, 1, 5, 2, 5, 8, 2, 8, 11, 4, 8, 5, -1, -1, -1, -1],
+    [10, 3, 11, 10, 1, 3, 9, 5, 4, -1, -1, -1, -1, -1, -1, -1],
+    [4, 9, 5, 0, 8, 1, 8, 10, 1, 8, 11, 10, -1, -1, -1, -1],
+    [5, 4, 0, 5, 0, 11, 5, 11, 10, 11, 0, 3, -1, -1, -1, -1],
+    [5, 4, 8, 5, 8, 10, 10, 8, 11, -1, -1, -1, -1, -1, -1, -1],
+    [9, 7, 8, 5, 7, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 3, 0, 9, 5, 3, 5, 7, 3, -1, -1, -1, -1, -1, -1, -1],
+    [0, 7, 8, 0, 1, 7, 1, 5, 7, -1, -1, -1, -1, -1, -1, -1],
+    [1, 5, 3, 3, 5, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 7, 8, 9, 5, 7, 10, 1, 2, -1, -1, -1, -1, -1, -1, -1],
+    [10, 1, 2, 9, 5, 0, 5, 3, 0, 5, 7, 3, -1, -1, -1, -1],
+    [8, 0, 2, 8, 2, 5, 8, 5, 7, 10, 5, 2, -1, -1, -1, -1],
+    [2, 10, 5, 2, 5, 3, 3, 5, 7, -1, -1, -1, -1, -1, -1, -1],
+    [7, 9, 5, 7, 8, 9, 3, 11, 2, -1, -1, -1, -1, -1, -1, -1],
+    [9, 5, 7, 9, 7, 2, 9, 2, 0, 2, 7, 11, -1, -1, -1, -1],
+    [2, 3, 11, 0, 1, 8, 1, 7, 8, 1, 5, 7, -1, -1, -1, -1],
+    [11, 2, 1, 11, 1, 7, 7, 1, 5, -1, -1, -1, -1, -1, -1, -1],
+    [9, 5, 8, 8, 5, 7, 10, 1, 3, 10, 3, 11, -1, -1, -1, -1],
+    [5, 7, 0, 5, 0, 9, 7, 11, 0, 1, 0, 10, 11, 10, 0, -1],
+    [11, 10, 0, 11, 0, 3, 10, 5, 0, 8, 0, 7, 5, 7, 0, -1],
+    [11, 10, 5, 7, 11, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [10, 6, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, 5, 10, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 0, 1, 5, 10, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 8, 3, 1, 9, 8, 5, 10, 6, -1, -1, -1, -1, -1, -1, -1],
+    [1, 6, 5, 2, 6, 1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 6, 5, 1, 2, 6, 3, 0, 8, -1, -1, -1, -1, -1, -1, -1],
+    [9, 6, 5, 9, 0, 6, 0, 2, 6, -1, -1, -1, -1, -1, -1, -1],
+    [5, 9, 8, 5, 8, 2, 5, 2, 6, 3, 2, 8, -1, -1, -1, -1],
+    [2, 3, 11, 10, 6, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [11, 0, 8, 11, 2, 0, 10, 6, 5, -1, -1, -1, -1, -1, -1, -1],
+    [0, 1, 9, 2, 3, 11, 5, 10, 6, -1, -1, -1, -1, -1, -1, -1],
+    [5, 10, 6, 1, 9, 2, 9, 11, 2, 9, 8, 11, -1, -1, -1, -1],
+    [6, 3, 11, 6, 5, 3, 5, 1, 3, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 11, 0, 11, 5, 0, 5, 1, 5, 11, 6, -1, -1, -1, -1],
+    [3, 11, 6, 0, 3, 6, 0, 6, 5, 0, 5, 9, -1, -1, -1, -1],
+    [6, 5, 9, 6, 9, 11, 11, 9, 8, -1, -1, -1, -1, -1, -1, -1],
+    [5, 10, 6, 4, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 3, 0, 4, 7, 3, 6, 5, 10, -1, -1, -1, -1, -1, -1, -1],
+    [1, 9, 0, 5, 10, 6, 8, 4, 7, -1, -1, -1, -1, -1, -1, -1],
+    [10, 6, 5, 1, 9, 7, 1, 7, 3, 7, 9, 4, -1, -1, -1, -1],
+    [6, 1, 2, 6, 5, 1, 4, 7, 8, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 5, 5, 2, 6, 3, 0, 4, 3, 4, 7, -1, -1, -1, -1],
+    [8, 4, 7, 9, 0, 5, 0, 6, 5, 0, 2, 6, -1, -1, -1, -1],
+    [7, 3, 9, 7, 9, 4, 3, 2, 9, 5, 9, 6, 2, 6, 9, -1],
+    [3, 11, 2, 7, 8, 4, 10, 6, 5, -1, -1, -1, -1, -1, -1, -1],
+    [5, 10, 6, 4, 7, 2, 4, 2, 0, 2, 7, 11, -1, -1, -1, -1],
+    [0, 1, 9, 4, 7, 8, 2, 3, 11, 5, 10, 6, -1, -1, -1, -1],
+    [9, 2, 1, 9, 11, 2, 9, 4, 11, 7, 11, 4, 5, 10, 6, -1],
+    [8, 4, 7, 3, 11, 5, 3, 5, 1, 5, 11, 6, -1, -1, -1, -1],
+    [5, 1, 11, 5, 11, 6, 1, 0, 11, 7, 11, 4, 0, 4, 11, -1],
+    [0, 5, 9, 0, 6, 5, 0, 3, 6, 11, 6, 3, 8, 4, 7, -1],
+    [6, 5, 9, 6, 9, 11, 4, 7, 9, 7, 11, 9, -1, -1, -1, -1],
+    [10, 4, 9, 6, 4, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 10, 6, 4, 9, 10, 0, 8, 3, -1, -1, -1, -1, -1, -1, -1],
+    [10, 0, 1, 10, 6, 0, 6, 4, 0, -1, -1, -1, -1, -1, -1, -1],
+    [8, 3, 1, 8, 1, 6, 8, 6, 4, 6, 1, 10, -1, -1, -1, -1],
+    [1, 4, 9, 1, 2, 4, 2, 6, 4, -1, -1, -1, -1, -1, -1, -1],
+    [3, 0, 8, 1, 2, 9, 2, 4, 9, 2, 6, 4, -1, -1, -1, -1],
+    [0, 2, 4, 4, 2, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [8, 3, 2, 8, 2, 4, 4, 2, 6, -1, -1, -1, -1, -1, -1, -1],
+    [10, 4, 9, 10, 6, 4, 11, 2, 3, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 2, 2, 8, 11, 4, 9, 10, 4, 10, 6, -1, -1, -1, -1],
+    [3, 11, 2, 0, 1, 6, 0, 6, 4, 6, 1, 10, -1, -1, -1, -1],
+    [6, 4, 1, 6, 1, 10, 4, 8, 1, 2, 1, 11, 8, 11, 1, -1],
+    [9, 6, 4, 9, 3, 6, 9, 1, 3, 11, 6, 3, -1, -1, -1, -1],
+    [8, 11, 1, 8, 1, 0, 11, 6, 1, 9, 1, 4, 6, 4, 1, -1],
+    [3, 11, 6, 3, 6, 0, 0, 6, 4, -1, -1, -1, -1, -1, -1, -1],
+    [6, 4, 8, 11, 6, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [7, 10, 6, 7, 8, 10, 8, 9, 10, -1, -1, -1, -1, -1, -1, -1],
+    [0, 7, 3, 0, 10, 7, 0, 9, 10, 6, 7, 10, -1, -1, -1, -1],
+    [10, 6, 7, 1, 10, 7, 1, 7, 8, 1, 8, 0, -1, -1, -1, -1],
+    [10, 6, 7, 10, 7, 1, 1, 7, 3, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 6, 1, 6, 8, 1, 8, 9, 8, 6, 7, -1, -1, -1, -1],
+    [2, 6, 9, 2, 9, 1, 6, 7, 9, 0, 9, 3, 7, 3, 9, -1],
+    [7, 8, 0, 7, 0, 6, 6, 0, 2, -1, -1, -1, -1, -1, -1, -1],
+    [7, 3, 2, 6, 7, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [2, 3, 11, 10, 6, 8, 10, 8, 9, 8, 6, 7, -1, -1, -1, -1],
+    [2, 0, 7, 2, 7, 11, 0, 9, 7, 6, 7, 10, 9, 10, 7, -1],
+    [1, 8, 0, 1, 7, 8, 1, 10, 7, 6, 7, 10, 2, 3, 11, -1],
+    [11, 2, 1, 11, 1, 7, 10, 6, 1, 6, 7, 1, -1, -1, -1, -1],
+    [8, 9, 6, 8, 6, 7, 9, 1, 6, 11, 6, 3, 1, 3, 6, -1],
+    [0, 9, 1, 11, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [7, 8, 0, 7, 0, 6, 3, 11, 0, 11, 6, 0, -1, -1, -1, -1],
+    [7, 11, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [7, 6, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 0, 8, 11, 7, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 1, 9, 11, 7, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [8, 1, 9, 8, 3, 1, 11, 7, 6, -1, -1, -1, -1, -1, -1, -1],
+    [10, 1, 2, 6, 11, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, 3, 0, 8, 6, 11, 7, -1, -1, -1, -1, -1, -1, -1],
+    [2, 9, 0, 2, 10, 9, 6, 11, 7, -1, -1, -1, -1, -1, -1, -1],
+    [6, 11, 7, 2, 10, 3, 10, 8, 3, 10, 9, 8, -1, -1, -1, -1],
+    [7, 2, 3, 6, 2, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [7, 0, 8, 7, 6, 0, 6, 2, 0, -1, -1, -1, -1, -1, -1, -1],
+    [2, 7, 6, 2, 3, 7, 0, 1, 9, -1, -1, -1, -1, -1, -1, -1],
+    [1, 6, 2, 1, 8, 6, 1, 9, 8, 8, 7, 6, -1, -1, -1, -1],
+    [10, 7, 6, 10, 1, 7, 1, 3, 7, -1, -1, -1, -1, -1, -1, -1],
+    [10, 7, 6, 1, 7, 10, 1, 8, 7, 1, 0, 8, -1, -1, -1, -1],
+    [0, 3, 7, 0, 7, 10, 0, 10, 9, 6, 10, 7, -1, -1, -1, -1],
+    [7, 6, 10, 7, 10, 8, 8, 10, 9, -1, -1, -1, -1, -1, -1, -1],
+    [6, 8, 4, 11, 8, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 6, 11, 3, 0, 6, 0, 4, 6, -1, -1, -1, -1, -1, -1, -1],
+    [8, 6, 11, 8, 4, 6, 9, 0, 1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 4, 6, 9, 6, 3, 9, 3, 1, 11, 3, 6, -1, -1, -1, -1],
+    [6, 8, 4, 6, 11, 8, 2, 10, 1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, 3, 0, 11, 0, 6, 11, 0, 4, 6, -1, -1, -1, -1],
+    [4, 11, 8, 4, 6, 11, 0, 2, 9, 2, 10, 9, -1, -1, -1, -1],
+    [10, 9, 3, 10, 3, 2, 9, 4, 3, 11, 3, 6, 4, 6, 3, -1],
+    [8, 2, 3, 8, 4, 2, 4, 6, 2, -1, -1, -1, -1, -1, -1, -1],
+    [0, 4, 2, 4, 6, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 9, 0, 2, 3, 4, 2, 4, 6, 4, 3, 8, -1, -1, -1, -1],
+    [1, 9, 4, 1, 4, 2, 2, 4, 6, -1, -1, -1, -1, -1, -1, -1],
+    [8, 1, 3, 8, 6, 1, 8, 4, 6, 6, 10, 1, -1, -1, -1, -1],
+    [10, 1, 0, 10, 0, 6, 6, 0, 4, -1, -1, -1, -1, -1, -1, -1],
+    [4, 6, 3, 4, 3, 8, 6, 10, 3, 0, 3, 9, 10, 9, 3, -1],
+    [10, 9, 4, 6, 10, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 9, 5, 7, 6, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, 4, 9, 5, 11, 7, 6, -1, -1, -1, -1, -1, -1, -1],
+    [5, 0, 1, 5, 4, 0, 7, 6, 11, -1, -1, -1, -1, -1, -1, -1],
+    [11, 7, 6, 8, 3, 4, 3, 5, 4, 3, 1, 5, -1, -1, -1, -1],
+    [9, 5, 4, 10, 1, 2, 7, 6, 11, -1, -1, -1, -1, -1, -1, -1],
+    [6, 11, 7, 1, 2, 10, 0, 8, 3, 4, 9, 5, -1, -1, -1, -1],
+    [7, 6, 11, 5, 4, 10, 4, 2, 10, 4, 0, 2, -1, -1, -1, -1],
+    [3, 4, 8, 3, 5, 4, 3, 2, 5, 10, 5, 2, 11, 7, 6, -1],
+    [7, 2, 3, 7, 6, 2, 5, 4, 9, -1, -1, -1, -1, -1, -1, -1],
+    [9, 5, 4, 0, 8, 6, 0, 6, 2, 6, 8, 7, -1, -1, -1, -1],
+    [3, 6, 2, 3, 7, 6, 1, 5, 0, 5, 4, 0, -1, -1, -1, -1],
+    [6, 2, 8, 6, 8, 7, 2, 1, 8, 4, 8, 5, 1, 5, 8, -1],
+    [9, 5, 4, 10, 1, 6, 1, 7, 6, 1, 3, 7, -1, -1, -1, -1],
+    [1, 6, 10, 1, 7, 6, 1, 0, 7, 8, 7, 0, 9, 5, 4, -1],
+    [4, 0, 10, 4, 10, 5, 0, 3, 10, 6, 10, 7, 3, 7, 10, -1],
+    [7, 6, 10, 7, 10, 8, 5, 4, 10, 4, 8, 10, -1, -1, -1, -1],
+    [6, 9, 5, 6, 11, 9, 11, 8, 9, -1, -1, -1, -1, -1, -1, -1],
+    [3, 6, 11, 0, 6, 3, 0, 5, 6, 0, 9, 5, -1, -1, -1, -1],
+    [0, 11, 8, 0, 5, 11, 0, 1, 5, 5, 6, 11, -1, -1, -1, -1],
+    [6, 11, 3, 6, 3, 5, 5, 3, 1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 10, 9, 5, 11, 9, 11, 8, 11, 5, 6, -1, -1, -1, -1],
+    [0, 11, 3, 0, 6, 11, 0, 9, 6, 5, 6, 9, 1, 2, 10, -1],
+    [11, 8, 5, 11, 5, 6, 8, 0, 5, 10, 5, 2, 0, 2, 5, -1],
+    [6, 11, 3, 6, 3, 5, 2, 10, 3, 10, 5, 3, -1, -1, -1, -1],
+    [5, 8, 9, 5, 2, 8, 5, 6, 2, 3, 8, 2, -1, -1, -1, -1],
+    [9, 5, 6, 9, 6, 0, 0, 6, 2, -1, -1, -1, -1, -1, -1, -1],
+    [1, 5, 8, 1, 8, 0, 5, 6, 8, 3, 8, 2, 6, 2, 8, -1],
+    [1, 5, 6, 2, 1, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 3, 6, 1, 6, 10, 3, 8, 6, 5, 6, 9, 8, 9, 6, -1],
+    [10, 1, 0, 10, 0, 6, 9, 5, 0, 5, 6, 0, -1, -1, -1, -1],
+    [0, 3, 8, 5, 6, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [10, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [11, 5, 10, 7, 5, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [11, 5, 10, 11, 7, 5, 8, 3, 0, -1, -1, -1, -1, -1, -1, -1],
+    [5, 11, 7, 5, 10, 11, 1, 9, 0, -1, -1, -1, -1, -1, -1, -1],
+    [10, 7, 5, 10, 11, 7, 9, 8, 1, 8, 3, 1, -1, -1, -1, -1],
+    [11, 1, 2, 11, 7, 1, 7, 5, 1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, 1, 2, 7, 1, 7, 5, 7, 2, 11, -1, -1, -1, -1],
+    [9, 7, 5, 9, 2, 7, 9, 0, 2, 2, 11, 7, -1, -1, -1, -1],
+    [7, 5, 2, 7, 2, 11, 5, 9, 2, 3, 2, 8, 9, 8, 2, -1],
+    [2, 5, 10, 2, 3, 5, 3, 7, 5, -1, -1, -1, -1, -1, -1, -1],
+    [8, 2, 0, 8, 5, 2, 8, 7, 5, 10, 2, 5, -1, -1, -1, -1],
+    [9, 0, 1, 5, 10, 3, 5, 3, 7, 3, 10, 2, -1, -1, -1, -1],
+    [9, 8, 2, 9, 2, 1, 8, 7, 2, 10, 2, 5, 7, 5, 2, -1],
+    [1, 3, 5, 3, 7, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 7, 0, 7, 1, 1, 7, 5, -1, -1, -1, -1, -1, -1, -1],
+    [9, 0, 3, 9, 3, 5, 5, 3, 7, -1, -1, -1, -1, -1, -1, -1],
+    [9, 8, 7, 5, 9, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [5, 8, 4, 5, 10, 8, 10, 11, 8, -1, -1, -1, -1, -1, -1, -1],
+    [5, 0, 4, 5, 11, 0, 5, 10, 11, 11, 3, 0, -1, -1, -1, -1],
+    [0, 1, 9, 8, 4, 10, 8, 10, 11, 10, 4, 5, -1, -1, -1, -1],
+    [10, 11, 4, 10, 4, 5, 11, 3, 4, 9, 4, 1, 3, 1, 4, -1],
+    [2, 5, 1, 2, 8, 5, 2, 11, 8, 4, 5, 8, -1, -1, -1, -1],
+    [0, 4, 11, 0, 11, 3, 4, 5, 11, 2, 11, 1, 5, 1, 11, -1],
+    [0, 2, 5, 0, 5, 9, 2, 11, 5, 4, 5, 8, 11, 8, 5, -1],
+    [9, 4, 5, 2, 11, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [2, 5, 10, 3, 5, 2, 3, 4, 5, 3, 8, 4, -1, -1, -1, -1],
+    [5, 10, 2, 5, 2, 4, 4, 2, 0, -1, -1, -1, -1, -1, -1, -1],
+    [3, 10, 2, 3, 5, 10, 3, 8, 5, 4, 5, 8, 0, 1, 9, -1],
+    [5, 10, 2, 5, 2, 4, 1, 9, 2, 9, 4, 2, -1, -1, -1, -1],
+    [8, 4, 5, 8, 5, 3, 3, 5, 1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 4, 5, 1, 0, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [8, 4, 5, 8, 5, 3, 9, 0, 5, 0, 3, 5, -1, -1, -1, -1],
+    [9, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 11, 7, 4, 9, 11, 9, 10, 11, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 3, 4, 9, 7, 9, 11, 7, 9, 10, 11, -1, -1, -1, -1],
+    [1, 10, 11, 1, 11, 4, 1, 4, 0, 7, 4, 11, -1, -1, -1, -1],
+    [3, 1, 4, 3, 4, 8, 1, 10, 4, 7, 4, 11, 10, 11, 4, -1],
+    [4, 11, 7, 9, 11, 4, 9, 2, 11, 9, 1, 2, -1, -1, -1, -1],
+    [9, 7, 4, 9, 11, 7, 9, 1, 11, 2, 11, 1, 0, 8, 3, -1],
+    [11, 7, 4, 11, 4, 2, 2, 4, 0, -1, -1, -1, -1, -1, -1, -1],
+    [11, 7, 4, 11, 4, 2, 8, 3, 4, 3, 2, 4, -1, -1, -1, -1],
+    [2, 9, 10, 2, 7, 9, 2, 3, 7, 7, 4, 9, -1, -1, -1, -1],
+    [9, 10, 7, 9, 7, 4, 10, 2, 7, 8, 7, 0, 2, 0, 7, -1],
+    [3, 7, 10, 3, 10, 2, 7, 4, 10, 1, 10, 0, 4, 0, 10, -1],
+    [1, 10, 2, 8, 7, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 9, 1, 4, 1, 7, 7, 1, 3, -1, -1, -1, -1, -1, -1, -1],
+    [4, 9, 1, 4, 1, 7, 0, 8, 1, 8, 7, 1, -1, -1, -1, -1],
+    [4, 0, 3, 7, 4, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [4, 8, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [9, 10, 8, 10, 11, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 0, 9, 3, 9, 11, 11, 9, 10, -1, -1, -1, -1, -1, -1, -1],
+    [0, 1, 10, 0, 10, 8, 8, 10, 11, -1, -1, -1, -1, -1, -1, -1],
+    [3, 1, 10, 11, 3, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 2, 11, 1, 11, 9, 9, 11, 8, -1, -1, -1, -1, -1, -1, -1],
+    [3, 0, 9, 3, 9, 11, 1, 2, 9, 2, 11, 9, -1, -1, -1, -1],
+    [0, 2, 11, 8, 0, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [3, 2, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [2, 3, 8, 2, 8, 10, 10, 8, 9, -1, -1, -1, -1, -1, -1, -1],
+    [9, 10, 2, 0, 9, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [2, 3, 8, 2, 8, 10, 0, 1, 8, 1, 10, 8, -1, -1, -1, -1],
+    [1, 10, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [1, 3, 8, 9, 1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 9, 1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 3, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A lone transparent voxel with nothing above or below it (air on both
+    /// sides, from this type's point of view) shows both its faces.
+    #[test]
+    fn transparent_face_masks_visible_against_air() {
+        let same_type = 0b01u64;
+        assert_eq!(transparent_face_masks(same_type, 0), (same_type, same_type));
+    }
+
+    /// Two stacked voxels of the *same* transparent type hide the face
+    /// between them (it's an internal, invisible boundary) but keep their two
+    /// outer faces.
+    #[test]
+    fn transparent_face_masks_culls_against_same_type_neighbor() {
+        let same_type = 0b11u64;
+        let (descending, ascending) = transparent_face_masks(same_type, 0);
+        assert_eq!(descending, 0b01, "bottom voxel's outer (downward) face stays visible");
+        assert_eq!(ascending, 0b10, "top voxel's outer (upward) face stays visible");
+    }
+
+    /// A transparent voxel with an opaque neighbor above it hides the face
+    /// touching that neighbor, but keeps the face on its other side.
+    #[test]
+    fn transparent_face_masks_culls_against_opaque_neighbor() {
+        let same_type = 0b01u64;
+        let opaque = 0b10u64;
+        assert_eq!(transparent_face_masks(same_type, opaque), (0b01, 0b00));
+    }
+
+    /// A *different* transparent type one voxel over isn't represented in
+    /// either mask this call sees, so from this type's perspective it's
+    /// indistinguishable from air — both faces stay visible, which is what
+    /// lets e.g. glass sit directly next to water.
+    #[test]
+    fn transparent_face_masks_visible_against_different_type() {
+        let same_type = 0b01u64;
+        assert_eq!(transparent_face_masks(same_type, 0), (same_type, same_type));
+    }
+
+    /// Every greedy quad's tangent has to be a unit vector, with a +-1
+    /// handedness sign in `w` (the real bitangent is
+    /// `cross(normal, tangent.xyz) * tangent.w`).
+    #[test]
+    fn face_tangent_is_unit_length_with_valid_handedness() {
+        for face_dir in [
+            FaceDir::Up,
+            FaceDir::Down,
+            FaceDir::Left,
+            FaceDir::Right,
+            FaceDir::Forward,
+            FaceDir::Back,
+        ] {
+            let tangent = face_tangent(face_dir);
+            assert!((tangent.truncate().length() - 1.0).abs() < f32::EPSILON);
+            assert!(tangent.w == 1.0 || tangent.w == -1.0);
+        }
+    }
+
+    /// `cull_faces_simd` must produce bit-for-bit the same transitions as the
+    /// scalar path for every column, including the ragged tail that doesn't
+    /// fill a full SIMD lane (`CHUNK_SIZE_P` isn't a multiple of 8).
+    #[cfg(feature = "portable_simd")]
+    #[test]
+    fn cull_faces_simd_matches_scalar() {
+        let mut axis_cols = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 3];
+        for axis in axis_cols.iter_mut() {
+            for (z, row) in axis.iter_mut().enumerate() {
+                for (x, col) in row.iter_mut().enumerate() {
+                    *col = (z as u64).wrapping_mul(0x9E3779B97F4A7C15) ^ (x as u64).rotate_left(7);
+                }
+            }
+        }
+
+        let mut scalar_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
+        let mut simd_masks = [[[0u64; CHUNK_SIZE_P]; CHUNK_SIZE_P]; 6];
+        cull_faces_scalar(&axis_cols, &mut scalar_masks);
+        cull_faces_simd(&axis_cols, &mut simd_masks);
+
+        assert_eq!(scalar_masks, simd_masks);
+    }
+
+    /// A fully-solid and a fully-air cube both sit entirely on one side of the
+    /// isolevel, so no edge should be active and no triangles should be
+    /// emitted for either.
+    #[test]
+    fn mc_cube_index_uniform_cells_produce_no_triangles() {
+        let all_solid = [1.0f32; 8];
+        let all_air = [0.0f32; 8];
+
+        let solid_index = mc_cube_index(&all_solid);
+        let air_index = mc_cube_index(&all_air);
+
+        assert_eq!(MC_EDGE_TABLE[solid_index as usize], 0);
+        assert_eq!(MC_EDGE_TABLE[air_index as usize], 0);
+        assert_eq!(MC_TRIANGLE_TABLE[solid_index as usize][0], -1);
+        assert_eq!(MC_TRIANGLE_TABLE[air_index as usize][0], -1);
+    }
+
+    /// Corners 1, 2, 4 and 7 below the isolevel (air) and the rest above it
+    /// (solid) must land on cube index 150 under the "bit set when below
+    /// isolevel" convention the tables were generated against, not its
+    /// complement 105 — those two rows triangulate the same ambiguous saddle
+    /// with different face diagonals, so picking the wrong one silently
+    /// produces a different (wrong) surface rather than just flipped winding.
+    #[test]
+    fn mc_cube_index_matches_table_convention_on_an_ambiguous_case() {
+        let mut corner_density = [1.0f32; 8];
+        for i in [1usize, 2, 4, 7] {
+            corner_density[i] = 0.0;
+        }
+
+        let cube_index = mc_cube_index(&corner_density);
+        assert_eq!(cube_index, 150);
+        assert_ne!(
+            MC_TRIANGLE_TABLE[150], MC_TRIANGLE_TABLE[105],
+            "rows 150 and 105 are known to triangulate differently; a table/convention \
+             mismatch would otherwise go unnoticed if they happened to agree"
+        );
+        assert_eq!(MC_EDGE_TABLE[cube_index as usize], 0xf55);
+    }
+}
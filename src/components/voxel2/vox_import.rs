@@ -0,0 +1,185 @@
+//! Imports MagicaVoxel `.vox` models into this crate's chunk representation so
+//! authored voxel art can be meshed with [`build_chunk_mesh`](crate::components::voxel2::meshing::greedyMesherOptimized_annotated::build_chunk_mesh)
+//! just like any other chunk, instead of going through a one-quad-per-voxel path.
+//!
+//! NOTE: this tree has no `Cargo.toml` yet; once one exists it needs a
+//! `dot_vox` dependency for the parsing below to build.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use bevy::math::{ivec3, IVec3};
+
+use crate::chunks_refs::{Chunk, ChunksRefs};
+use crate::constants::CHUNK_SIZE;
+use crate::utils::vec3_to_index;
+use crate::voxel::BlockData;
+
+/// Maps a `.vox` palette index (1-255, 0 is always empty) to the block type ID
+/// the mesher's packed vertex format expects.
+pub type PaletteMaterialTable = HashMap<u8, u32>;
+
+#[derive(Debug)]
+pub enum VoxImportError {
+    Io(std::io::Error),
+    /// `dot_vox` reports parse failures as a bare string.
+    Parse(String),
+    /// The file parsed but contained no models.
+    Empty,
+}
+
+impl std::fmt::Display for VoxImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VoxImportError::Io(e) => write!(f, "failed to read .vox file: {e}"),
+            VoxImportError::Parse(e) => write!(f, "failed to parse .vox file: {e}"),
+            VoxImportError::Empty => write!(f, ".vox file contained no models"),
+        }
+    }
+}
+
+impl std::error::Error for VoxImportError {}
+
+/// `.vox` stores Z as up and is right-handed; this crate is Y up and also
+/// right-handed, so mapping Y/Z straight across would be a reflection
+/// (determinant -1), mirroring every imported model and reversing its
+/// winding/normals. Negating the new Z (old Y) keeps the mapping a rotation
+/// instead, preserving handedness.
+#[inline]
+fn vox_to_world(x: u8, y: u8, z: u8) -> IVec3 {
+    ivec3(x as i32, z as i32, -(y as i32))
+}
+
+/// Loads a `.vox` file and converts its first model into one `ChunksRefs` per
+/// `CHUNK_SIZE`-aligned chunk the model occupies, plus a palette -> block type
+/// table the mesher's packed `block_type` field can index into.
+///
+/// Each returned `ChunksRefs` is already the same 3x3x3 neighbor window
+/// `build_chunk_mesh` expects, so callers can mesh the imported model directly
+/// without re-deriving chunk adjacency themselves. Any chunk region that ends up
+/// entirely empty or entirely one block type is stored in the compressed
+/// single-voxel format (`chunk.voxels.len() == 1`), the same way procedurally
+/// generated chunks are.
+pub fn import_vox_model(
+    path: &Path,
+    palette_to_block_type: impl Fn(u8) -> u32,
+) -> Result<(Vec<ChunksRefs>, PaletteMaterialTable), VoxImportError> {
+    let bytes = std::fs::read(path).map_err(VoxImportError::Io)?;
+    let data = dot_vox::load_bytes(&bytes).map_err(VoxImportError::Parse)?;
+    let model = data.models.first().ok_or(VoxImportError::Empty)?;
+
+    // `.vox` voxels carry a 1-255 palette index (0 means empty); build the
+    // palette -> material table once up front from whichever indices are
+    // actually used by this model.
+    let mut palette_table = PaletteMaterialTable::new();
+    for voxel in &model.voxels {
+        palette_table
+            .entry(voxel.i)
+            .or_insert_with(|| palette_to_block_type(voxel.i));
+    }
+
+    let chunk_size = CHUNK_SIZE as i32;
+    let mut chunk_voxels: HashMap<IVec3, Vec<u8>> = HashMap::new();
+    for voxel in &model.voxels {
+        let world_pos = vox_to_world(voxel.x, voxel.y, voxel.z);
+        let chunk_pos = world_pos.div_euclid(IVec3::splat(chunk_size));
+        let local_pos = world_pos.rem_euclid(IVec3::splat(chunk_size));
+
+        let voxels = chunk_voxels
+            .entry(chunk_pos)
+            .or_insert_with(|| vec![0u8; CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE]);
+        let index = (local_pos.z as usize * CHUNK_SIZE + local_pos.y as usize) * CHUNK_SIZE
+            + local_pos.x as usize;
+        voxels[index] = voxel.i;
+    }
+
+    // Build one Rc<Chunk> per occupied chunk position so neighbor windows below
+    // can cheaply share them instead of cloning voxel data per window.
+    let chunks_by_pos: HashMap<IVec3, Rc<Chunk>> = chunk_voxels
+        .iter()
+        .map(|(&pos, indices)| (pos, Rc::new(chunk_from_palette_indices(indices, &palette_table))))
+        .collect();
+    let empty_chunk = Rc::new(Chunk {
+        voxels: vec![BlockData::empty()],
+    });
+
+    let mut chunks_refs = Vec::with_capacity(chunks_by_pos.len());
+    for &chunk_pos in chunks_by_pos.keys() {
+        let mut window = vec![empty_chunk.clone(); 27];
+        for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let neighbor_pos = chunk_pos + ivec3(dx, dy, dz);
+                    let neighbor = chunks_by_pos
+                        .get(&neighbor_pos)
+                        .cloned()
+                        .unwrap_or_else(|| empty_chunk.clone());
+                    window[vec3_to_index(ivec3(dx + 1, dy + 1, dz + 1), 3)] = neighbor;
+                }
+            }
+        }
+        chunks_refs.push(ChunksRefs { chunks: window });
+    }
+
+    Ok((chunks_refs, palette_table))
+}
+
+fn chunk_from_palette_indices(indices: &[u8], palette_table: &PaletteMaterialTable) -> Chunk {
+    // Uniform regions (a fully empty chunk, or one solid color filling it)
+    // compress down to a single voxel so `build_chunk_mesh`'s
+    // `chunk.voxels.len() == 1` fast path kicks in, the same as for generated
+    // terrain.
+    let first = indices[0];
+    if indices.iter().all(|v| *v == first) {
+        Chunk {
+            voxels: vec![block_data_for(first, palette_table)],
+        }
+    } else {
+        Chunk {
+            voxels: indices
+                .iter()
+                .map(|v| block_data_for(*v, palette_table))
+                .collect(),
+        }
+    }
+}
+
+fn block_data_for(palette_index: u8, palette_table: &PaletteMaterialTable) -> BlockData {
+    if palette_index == 0 {
+        BlockData::empty()
+    } else {
+        BlockData::from_block_type(palette_table[&palette_index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Swapping two axes without a sign flip is a reflection (determinant
+    /// -1), which would mirror every imported model's winding/normals. The
+    /// mapping has to stay a rotation (determinant +1) of the `.vox` basis
+    /// vectors to preserve handedness.
+    #[test]
+    fn vox_to_world_preserves_handedness() {
+        let wx = vox_to_world(1, 0, 0);
+        let wy = vox_to_world(0, 1, 0);
+        let wz = vox_to_world(0, 0, 1);
+
+        // Determinant of the matrix whose columns are the images of the
+        // standard basis vectors, i.e. the mapping's own orientation.
+        let det = wx.x * (wy.y * wz.z - wy.z * wz.y) - wx.y * (wy.x * wz.z - wy.z * wz.x)
+            + wx.z * (wy.x * wz.y - wy.y * wz.x);
+
+        assert_eq!(det, 1, "mapping mirrors the model instead of rotating it");
+    }
+
+    #[test]
+    fn vox_to_world_maps_up_axis_and_horizontal_plane() {
+        // .vox Z-up becomes this crate's Y-up, and .vox Y flips sign into Z.
+        assert_eq!(vox_to_world(0, 0, 1), IVec3::new(0, 1, 0));
+        assert_eq!(vox_to_world(0, 1, 0), IVec3::new(0, 0, -1));
+        assert_eq!(vox_to_world(1, 0, 0), IVec3::new(1, 0, 0));
+    }
+}